@@ -0,0 +1,47 @@
+// 近傍探索用の一様グリッド（ブロードフェーズ）
+//
+// セルサイズをボールの最大直径程度に取ることで、総当たり O(n^2) の
+// 重なり判定を近傍セルだけを見る探索に落とし込む。
+use std::collections::HashMap;
+
+pub(crate) struct SpatialGrid {
+    cell_size: f64,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl SpatialGrid {
+    pub(crate) fn new(cell_size: f64) -> Self {
+        SpatialGrid {
+            cell_size: cell_size.max(1.0),
+            cells: HashMap::new(),
+        }
+    }
+
+    fn cell_of(&self, x: f64, y: f64) -> (i32, i32) {
+        (
+            (x / self.cell_size).floor() as i32,
+            (y / self.cell_size).floor() as i32,
+        )
+    }
+
+    pub(crate) fn insert(&mut self, index: usize, x: f64, y: f64) {
+        self.cells
+            .entry(self.cell_of(x, y))
+            .or_insert_with(Vec::new)
+            .push(index);
+    }
+
+    // 自セルと隣接8セルに入っている候補インデックスをまとめて返す
+    pub(crate) fn neighbors(&self, x: f64, y: f64) -> Vec<usize> {
+        let (cx, cy) = self.cell_of(x, y);
+        let mut result = Vec::new();
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                if let Some(indices) = self.cells.get(&(cx + dx, cy + dy)) {
+                    result.extend_from_slice(indices);
+                }
+            }
+        }
+        result
+    }
+}
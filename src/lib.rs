@@ -1,9 +1,14 @@
 mod dom_util;
+mod grid;
+mod state;
 mod vec2d;
 
 use dom_util::*;
+use grid::SpatialGrid;
+use state::{AppState, MenuState, PausedState, RunningState, Transition};
 use vec2d::Vec2d;
 
+use rand::seq::SliceRandom;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
@@ -13,14 +18,55 @@ use wasm_bindgen::JsCast;
 
 use web_sys::console::log_1;
 
+// SEIRSモデルにおけるボールの健康状態
+#[derive(Clone, PartialEq)]
+enum Health {
+    Susceptible, // 未感染
+    Exposed { incubation_left: u32 }, // 潜伏期間中（残りティック数）
+    Infected, // 感染中
+    Recovered, // 回復済み（免疫あり）
+}
+
+// アプリ全体のシミュレーションモード
+#[derive(Clone, Copy, PartialEq)]
+enum SimMode {
+    Classic, // 従来のバウンドするだけのデモ
+    Seirs,   // SEIRS感染モデル
+}
+
+// ボールの描画形状
+#[derive(Clone, Copy, PartialEq)]
+enum Shape {
+    Circle,
+    Square,
+    Triangle,
+}
+
+// ボールが呼吸するように拡縮するアニメーションの周波数（ラジアン/秒）
+const PULSE_ANGULAR_FREQUENCY: f64 = 2.0;
+
+impl Shape {
+    fn from_str(s: &str) -> Shape {
+        match s {
+            "square" => Shape::Square,
+            "triangle" => Shape::Triangle,
+            _ => Shape::Circle,
+        }
+    }
+}
+
 #[derive(Clone)]
 struct Ball {
     position: Vec2d, // 位置
-    direction: Vec2d, // 動く方向
-    speed: f64, // 速さ
+    velocity: Vec2d, // 速度（方向と速さを合成したもの）
     size: f64, // 大きさ
     is_inverse: bool, // 反転するか
     color: String, // 色
+    health: Health, // SEIRSモードでの健康状態
+    shape: Shape, // 描画形状
+    angle: f64, // 現在の回転角（ラジアン）
+    angular_velocity: f64, // 角速度（ラジアン/秒）
+    scale_phase: f64, // 拡縮アニメーションの位相
 }
 
 #[derive(Serialize, Deserialize)]
@@ -28,6 +74,16 @@ pub struct AppOptions {
     pub canvas_id: String, // canvas属性のid
     pub window_x: Option<u32>, // 画面幅
     pub window_y: Option<u32>, // 画面の高さ
+    pub mode: Option<String>, // シミュレーションモード ("seirs" でSEIRSモデルを有効化)
+    pub beta: Option<f64>, // S→E の感染確率（1ティックあたり）
+    pub gamma: Option<f64>, // I→R の回復確率（1ティックあたり）
+    pub delta: Option<f64>, // R→S の免疫喪失確率（1ティックあたり）
+    pub incubation_ticks: Option<u32>, // E→I までの潜伏期間（ティック数）
+    pub initial_infected: Option<u32>, // 初期感染者数
+    pub restitution: Option<f64>, // 衝突の反発係数 e（1.0で完全弾性衝突）
+    pub tick_rate: Option<f64>, // シミュレーションの目標ティックレート（Hz）
+    pub default_shape: Option<String>, // ボールの描画形状 ("circle" | "square" | "triangle")
+    pub rotation_enabled: Option<bool>, // ボールを回転させるか
 }
 
 #[wasm_bindgen]
@@ -37,18 +93,56 @@ pub struct App {
     window_y: u32, // 画面高さ
     initial_n_balls: u32, // ボールの数
     balls: Vec<Box<Ball>>, // ボール
+    mode: SimMode, // シミュレーションモード
+    beta: f64, // S→E の感染確率
+    gamma: f64, // I→R の回復確率
+    delta: f64, // R→S の免疫喪失確率
+    incubation_ticks: u32, // 潜伏期間（ティック数）
+    initial_infected: u32, // 初期感染者数
+    restitution: f64, // 衝突の反発係数 e
+    tick_rate: f64, // シミュレーションの目標ティックレート（Hz）
+    input_closures: Vec<InputClosure>, // 入力リスナー用Closure（生存させておくために保持する）
+    state: Option<Box<dyn AppState>>, // 現在のアプリ状態（pause/menu/running）
+    default_shape: Shape, // ボールの描画形状
+    rotation_enabled: bool, // ボールを回転させるか
+}
+
+// マウス/キーボードのイベントリスナーとして登録したClosureを保持するための入れ物
+enum InputClosure {
+    Pointer(Closure<dyn FnMut(web_sys::MouseEvent)>),
+    Key(Closure<dyn FnMut(web_sys::KeyboardEvent)>),
 }
 
 #[wasm_bindgen]
 impl App {
     pub fn new(options: JsValue) -> App {
         let opts: AppOptions = options.into_serde().unwrap();
+        let mode = match opts.mode.as_deref() {
+            Some("seirs") => SimMode::Seirs,
+            _ => SimMode::Classic,
+        };
         App {
             window_x: opts.window_x.unwrap(),
             window_y: opts.window_y.unwrap(),
             context: context2d(&opts.canvas_id),
             initial_n_balls: 10,
             balls: vec![],
+            mode,
+            beta: opts.beta.unwrap_or(0.02),
+            gamma: opts.gamma.unwrap_or(0.005),
+            delta: opts.delta.unwrap_or(0.001),
+            incubation_ticks: opts.incubation_ticks.unwrap_or(90),
+            initial_infected: opts.initial_infected.unwrap_or(1),
+            restitution: opts.restitution.unwrap_or(0.9),
+            tick_rate: opts.tick_rate.unwrap_or(60.0),
+            input_closures: vec![],
+            state: Some(Box::new(MenuState)),
+            default_shape: opts
+                .default_shape
+                .as_deref()
+                .map(Shape::from_str)
+                .unwrap_or(Shape::Circle),
+            rotation_enabled: opts.rotation_enabled.unwrap_or(true),
         }
     }
 
@@ -63,99 +157,451 @@ impl App {
             let ball = Box::new(self.generate_ball());
             self.balls.push(ball);
         }
+
+        if self.mode == SimMode::Seirs {
+            self.seed_initial_infected();
+        }
+    }
+
+    // SEIRSモード開始時に、ランダムに選んだボールを初期感染者にする
+    fn seed_initial_infected(&mut self) {
+        let mut indices: Vec<usize> = (0..self.balls.len()).collect();
+        indices.shuffle(&mut rand::thread_rng());
+
+        let n = (self.initial_infected as usize).min(indices.len());
+        for &idx in indices.iter().take(n) {
+            self.balls[idx].health = Health::Infected;
+            self.balls[idx].color = health_color(&Health::Infected);
+        }
     }
 
     // ボールの生成
     fn generate_ball(&self) -> Ball {
+        let direction = Vec2d {
+            x: random_number(-0.5, 0.5),
+            y: random_number(-0.5, 0.5),
+        };
+        // speedは「目標ティックレートでの1ティックあたりの移動量」として決め、
+        // velocityは秒あたりの速度として保持する（moves()はdt秒分だけ進める）
+        let speed = random_number(5., 10.) * self.tick_rate;
         Ball {
             position: Vec2d {
                 x: random_number(0., self.window_x as f64),
                 y: random_number(0., self.window_y as f64),
             },
-            direction: Vec2d {
-                x: random_number(-0.5, 0.5),
-                y: random_number(-0.5, 0.5),
+            velocity: Vec2d {
+                x: direction.x * speed,
+                y: direction.y * speed,
             },
-            speed: random_number(5., 10.),
             size: random_number(50., 100.0),
             is_inverse: if random_number(- 1., 1.) > 0. {true} else {false},
-            color: ball_color()
+            color: ball_color(),
+            health: Health::Susceptible,
+            shape: self.default_shape,
+            angle: random_number(0., std::f64::consts::PI * 2.0),
+            angular_velocity: if self.rotation_enabled {
+                random_number(-1.0, 1.0)
+            } else {
+                0.0
+            },
+            scale_phase: random_number(0., std::f64::consts::PI * 2.0),
         }
     }
 
     pub fn on_animation_frame(&mut self) -> bool {
-        self.moves();
-        self.render();
+        let dt = 1.0 / self.tick_rate;
+        self.advance_state(dt);
+        self.render_current_state();
         true
     }
 
-    fn moves(&mut self) {
-        // direction に従って移動する
+    // 現在の状態のupdateを呼び出し、返ってきた遷移要求があれば適用する
+    fn advance_state(&mut self, dt: f64) {
+        let mut state = self.state.take().expect("state should always be present");
+        let transition = state.update(self, dt);
+        self.state = Some(state);
+
+        if let Some(transition) = transition {
+            self.apply_transition(transition);
+        }
+    }
+
+    // 現在の状態に描画を委譲する
+    fn render_current_state(&self) {
+        if let Some(state) = self.state.as_ref() {
+            state.render(self);
+        }
+    }
+
+    fn apply_transition(&mut self, transition: Transition) {
+        let paused_now = self.state.as_ref().map_or(false, |s| s.is_paused());
+        self.state = Some(match transition {
+            Transition::Start => Box::new(RunningState),
+            Transition::TogglePause => {
+                if paused_now {
+                    Box::new(RunningState)
+                } else {
+                    Box::new(PausedState)
+                }
+            }
+        });
+    }
+
+    // ポインタ操作: 左クリック(button=0)でボールを生成、右クリック(button=2)で最も近いボールを削除する
+    pub fn on_pointer(&mut self, x: f64, y: f64, button: i16) {
+        match button {
+            0 => {
+                let mut ball = self.generate_ball();
+                ball.position.x = x;
+                ball.position.y = y;
+                self.balls.push(Box::new(ball));
+            }
+            2 => self.remove_nearest_ball(x, y),
+            _ => {}
+        }
+    }
+
+    fn remove_nearest_ball(&mut self, x: f64, y: f64) {
+        let nearest = self
+            .balls
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                let da = (a.position.x - x).powi(2) + (a.position.y - y).powi(2);
+                let db = (b.position.x - x).powi(2) + (b.position.y - y).powi(2);
+                da.partial_cmp(&db).unwrap()
+            })
+            .map(|(i, _)| i);
+
+        if let Some(i) = nearest {
+            self.balls.remove(i);
+        }
+    }
+
+    // キー入力: スペースキーでバウンド挙動をトグル、Pで一時停止、Enterでシミュレーション開始
+    pub fn on_key(&mut self, code: String) {
+        match code.as_str() {
+            "Space" => {
+                for ball in self.balls.iter_mut() {
+                    ball.is_inverse = !ball.is_inverse;
+                }
+            }
+            "KeyP" => self.apply_transition(Transition::TogglePause),
+            "Enter" => self.apply_transition(Transition::Start),
+            _ => {}
+        }
+    }
+
+    // 固定タイムステップ分だけシミュレーションを1ティック進める
+    pub(crate) fn tick(&mut self, dt: f64) {
+        self.moves(dt);
+        self.resolve_collisions();
+        if self.mode == SimMode::Seirs {
+            self.step_health();
+        }
+    }
+
+    // ボール同士の弾性衝突を解決する（広域判定にグリッドを利用）
+    fn resolve_collisions(&mut self) {
+        let cell_size = self
+            .balls
+            .iter()
+            .map(|b| b.size * 2.0)
+            .fold(1.0_f64, f64::max);
+        let mut grid = SpatialGrid::new(cell_size);
+        for (i, ball) in self.balls.iter().enumerate() {
+            grid.insert(i, ball.position.x, ball.position.y);
+        }
+
+        for i in 0..self.balls.len() {
+            let (ix, iy) = (self.balls[i].position.x, self.balls[i].position.y);
+            for j in grid.neighbors(ix, iy) {
+                if j > i {
+                    self.resolve_pair(i, j);
+                }
+            }
+        }
+    }
+
+    // 2つのボールが重なっていれば、質量(size^2に比例)を考慮した
+    // 弾性衝突の撃力を加え、めり込み分を法線方向に押し戻す
+    fn resolve_pair(&mut self, i: usize, j: usize) {
+        let dx = self.balls[j].position.x - self.balls[i].position.x;
+        let dy = self.balls[j].position.y - self.balls[i].position.y;
+        let dist = (dx * dx + dy * dy).sqrt();
+        let min_dist = self.balls[i].size + self.balls[j].size;
+        if dist >= min_dist || dist == 0. {
+            return;
+        }
+
+        let nx = dx / dist;
+        let ny = dy / dist;
+
+        let mass_i = self.balls[i].size * self.balls[i].size;
+        let mass_j = self.balls[j].size * self.balls[j].size;
+
+        let rvx = self.balls[j].velocity.x - self.balls[i].velocity.x;
+        let rvy = self.balls[j].velocity.y - self.balls[i].velocity.y;
+        let vel_along_normal = rvx * nx + rvy * ny;
+
+        if vel_along_normal < 0. {
+            let e = self.restitution;
+            let impulse = -(1. + e) * vel_along_normal / (1. / mass_i + 1. / mass_j);
+
+            self.balls[i].velocity.x -= impulse * nx / mass_i;
+            self.balls[i].velocity.y -= impulse * ny / mass_i;
+            self.balls[j].velocity.x += impulse * nx / mass_j;
+            self.balls[j].velocity.y += impulse * ny / mass_j;
+        }
+
+        // 位置補正：質量比に応じてめり込み分を押し戻し、くっつきを防ぐ
+        let overlap = min_dist - dist;
+        let total_mass = mass_i + mass_j;
+        let correction_i = overlap * (mass_j / total_mass);
+        let correction_j = overlap * (mass_i / total_mass);
+        self.balls[i].position.x -= nx * correction_i;
+        self.balls[i].position.y -= ny * correction_i;
+        self.balls[j].position.x += nx * correction_j;
+        self.balls[j].position.y += ny * correction_j;
+    }
+
+    // SEIRSの状態遷移を1ティック分進める
+    fn step_health(&mut self) {
+        // ボールの最大直径をセルサイズにしたグリッドへ登録する（ブロードフェーズ）
+        let cell_size = self
+            .balls
+            .iter()
+            .map(|b| b.size * 2.0)
+            .fold(1.0_f64, f64::max);
+        let mut grid = SpatialGrid::new(cell_size);
+        for (i, ball) in self.balls.iter().enumerate() {
+            grid.insert(i, ball.position.x, ball.position.y);
+        }
+
+        // S -> E: 感染者と重なった感受性者を確率betaで曝露する
+        let mut newly_exposed = vec![false; self.balls.len()];
+        for i in 0..self.balls.len() {
+            if self.balls[i].health != Health::Infected {
+                continue;
+            }
+            let (ix, iy, isize) = (self.balls[i].position.x, self.balls[i].position.y, self.balls[i].size);
+            for j in grid.neighbors(ix, iy) {
+                if j == i || self.balls[j].health != Health::Susceptible {
+                    continue;
+                }
+                let dx = self.balls[j].position.x - ix;
+                let dy = self.balls[j].position.y - iy;
+                let dist = (dx * dx + dy * dy).sqrt();
+                if dist < isize + self.balls[j].size && random_number(0., 1.) < self.beta {
+                    newly_exposed[j] = true;
+                }
+            }
+        }
+
+        for (i, ball) in self.balls.iter_mut().enumerate() {
+            match ball.health {
+                Health::Susceptible if newly_exposed[i] => {
+                    ball.health = Health::Exposed {
+                        incubation_left: self.incubation_ticks,
+                    };
+                }
+                Health::Exposed { incubation_left } => {
+                    ball.health = if incubation_left <= 1 {
+                        Health::Infected
+                    } else {
+                        Health::Exposed {
+                            incubation_left: incubation_left - 1,
+                        }
+                    };
+                }
+                Health::Infected if random_number(0., 1.) < self.gamma => {
+                    ball.health = Health::Recovered;
+                }
+                Health::Recovered if random_number(0., 1.) < self.delta => {
+                    ball.health = Health::Susceptible;
+                }
+                _ => {}
+            }
+            ball.color = health_color(&ball.health);
+        }
+    }
+
+    fn moves(&mut self, dt: f64) {
+        // velocity(秒あたり) に dt をかけて移動する
         for ball in self.balls.iter_mut() {
-            ball.position.x = ball.position.x + ball.direction.x * ball.speed;
-            ball.position.y = ball.position.y + ball.direction.y * ball.speed;
+            ball.position.x = ball.position.x + ball.velocity.x * dt;
+            ball.position.y = ball.position.y + ball.velocity.y * dt;
 
             // 境界を超えたら反転する
             if ball.is_inverse {
                 if ball.position.x < 0. || ball.position.x > self.window_x as f64 {
-                    ball.position.x -= ball.direction.x;
-                    ball.direction.x = -ball.direction.x;
+                    ball.position.x -= ball.velocity.x * dt;
+                    ball.velocity.x = -ball.velocity.x;
                 }
                 if ball.position.y < 0. || ball.position.y > self.window_y as f64 {
-                    ball.position.y -= ball.direction.y;
-                    ball.direction.y = -ball.direction.y;
+                    ball.position.y -= ball.velocity.y * dt;
+                    ball.velocity.y = -ball.velocity.y;
                 }
             }
+
+            ball.angle += ball.angular_velocity * dt;
+            ball.scale_phase += PULSE_ANGULAR_FREQUENCY * dt;
         }
     }
 
-    // 描画
-    fn render(&self) {
+    // シミュレーションの描画（背景＋ボール）
+    pub(crate) fn render_scene(&self) {
         self.context.save();
         self.render_bg();
         self.render_balls(&self.balls);
         self.context.restore();
     }
 
+    // 一時停止中に重ねる半透明オーバーレイ
+    pub(crate) fn render_pause_overlay(&self) {
+        self.context.save();
+        self.context.set_fill_style(&JsValue::from("rgba(0, 0, 0, 0.5)"));
+        self.context
+            .fill_rect(0., 0., self.window_x as f64, self.window_y as f64);
+        self.context.restore();
+    }
+
+    // シミュレーション開始前に表示する操作案内
+    pub(crate) fn render_menu(&self) {
+        self.render_bg();
+        self.context.save();
+        self.context.set_fill_style(&JsValue::from("rgb(255, 255, 255, 1)"));
+        self.context.set_font("24px sans-serif");
+        self.context
+            .fill_text(
+                "Enter: start / Space: toggle bounce / P: pause",
+                20.,
+                40.,
+            )
+            .unwrap();
+        self.context.restore();
+    }
+
     // 背景の描画
     fn render_bg(&self) {
         self.context.set_fill_style(&JsValue::from(bg_color()));
         self.context.fill_rect(0., 0., self.window_x as f64, self.window_y as f64);
     }
 
-    // ボールの描画
+    // ボールの描画。position/angle/scale_phaseに応じてtransformを積んでから、
+    // ball.shapeに応じた図形をtransform原点(0, 0)基準で描く
     fn render_balls(&self, balls: &Vec<Box<Ball>>) {
-        for (_, ball) in balls.iter().enumerate() {
-            self.context.begin_path();
+        for ball in balls.iter() {
+            let pulse = 1.0 + 0.15 * ball.scale_phase.sin();
+
+            self.context.save();
             self.context
-                .arc(
-                    ball.position.x.into(),
-                    ball.position.y.into(),
-                    ball.size,
-                    0.,
-                    std::f64::consts::PI * 2.0,
-                )
+                .translate(ball.position.x, ball.position.y)
                 .unwrap();
+            self.context.rotate(ball.angle).unwrap();
+            self.context.scale(pulse, pulse).unwrap();
+
+            self.context.begin_path();
+            match ball.shape {
+                Shape::Circle => {
+                    self.context
+                        .arc(0., 0., ball.size, 0., std::f64::consts::PI * 2.0)
+                        .unwrap();
+                }
+                Shape::Square => {
+                    self.context
+                        .rect(-ball.size, -ball.size, ball.size * 2.0, ball.size * 2.0);
+                }
+                Shape::Triangle => {
+                    let r = ball.size;
+                    self.context.move_to(0., -r);
+                    self.context.line_to(r * 0.866, r * 0.5);
+                    self.context.line_to(-r * 0.866, r * 0.5);
+                    self.context.close_path();
+                }
+            }
             self.context.set_fill_style(&JsValue::from(&ball.color));
             self.context.fill();
+            self.context.restore();
         }
     }
 }
 
+// 長時間のポーズ明けにシミュレーションが固まらないよう、
+// 蓄積できる経過時間の上限を設ける（"spiral of death" 対策）
+const MAX_ACCUMULATED_SECONDS: f64 = 0.25;
+
 #[wasm_bindgen]
 pub fn start_animation(app: App) -> Result<(), JsValue> {
     let closure_owner_captured = Rc::new(RefCell::new(None));
     let closure_owner = closure_owner_captured.clone();
+    let tick_dt = 1.0 / app.tick_rate;
     let app_holder_captured = Rc::new(RefCell::new(app));
+    attach_input_listeners(&app_holder_captured)?;
+
+    let previous_time = Rc::new(RefCell::new(None::<f64>));
+    let accumulator = Rc::new(RefCell::new(0.0_f64));
+
+    *closure_owner.borrow_mut() = Some(Closure::wrap(Box::new(move |time: f64| {
+        let dt = match *previous_time.borrow() {
+            Some(prev) => ((time - prev) / 1000.0).max(0.0),
+            None => 0.0,
+        };
+        *previous_time.borrow_mut() = Some(time);
+
+        let mut acc = accumulator.borrow_mut();
+        *acc = (*acc + dt).min(MAX_ACCUMULATED_SECONDS);
+
+        let mut app = app_holder_captured.borrow_mut();
+        while *acc >= tick_dt {
+            app.advance_state(tick_dt);
+            *acc -= tick_dt;
+        }
+        app.render_current_state();
 
-    *closure_owner.borrow_mut() = Some(Closure::wrap(Box::new(move |_| {
-        app_holder_captured.borrow_mut().on_animation_frame();
         request_animation_frame(closure_owner_captured.borrow().as_ref().unwrap());
     }) as Box<dyn FnMut(f64)>));
     request_animation_frame(closure_owner.borrow().as_ref().unwrap());
     Ok(())
 }
 
+// canvasへのクリックとキー入力をApp::on_pointer/on_keyへ中継するリスナーを登録する
+fn attach_input_listeners(app: &Rc<RefCell<App>>) -> Result<(), JsValue> {
+    let canvas = app.borrow().context.canvas().unwrap();
+
+    let pointer_app = app.clone();
+    let pointer_closure = Closure::wrap(Box::new(move |event: web_sys::MouseEvent| {
+        event.prevent_default();
+        let x = event.offset_x() as f64;
+        let y = event.offset_y() as f64;
+        pointer_app.borrow_mut().on_pointer(x, y, event.button());
+    }) as Box<dyn FnMut(web_sys::MouseEvent)>);
+    canvas.add_event_listener_with_callback("mousedown", pointer_closure.as_ref().unchecked_ref())?;
+
+    // 右クリックのブラウザ標準コンテキストメニューを抑止する（ball削除はmousedownで処理済み）
+    let suppress_menu_closure = Closure::wrap(Box::new(move |event: web_sys::MouseEvent| {
+        event.prevent_default();
+    }) as Box<dyn FnMut(web_sys::MouseEvent)>);
+    canvas.add_event_listener_with_callback(
+        "contextmenu",
+        suppress_menu_closure.as_ref().unchecked_ref(),
+    )?;
+
+    let key_app = app.clone();
+    let key_closure = Closure::wrap(Box::new(move |event: web_sys::KeyboardEvent| {
+        key_app.borrow_mut().on_key(event.code());
+    }) as Box<dyn FnMut(web_sys::KeyboardEvent)>);
+    window().add_event_listener_with_callback("keydown", key_closure.as_ref().unchecked_ref())?;
+
+    app.borrow_mut().input_closures = vec![
+        InputClosure::Pointer(pointer_closure),
+        InputClosure::Pointer(suppress_menu_closure),
+        InputClosure::Key(key_closure),
+    ];
+
+    Ok(())
+}
+
 // アニメーションの更新をリクエスト
 fn request_animation_frame(f: &Closure<dyn FnMut(f64)>) {
     window()
@@ -177,6 +623,16 @@ fn bg_color() -> String {
     String::from("rgb(0, 0, 0, 1)")
 }
 
+// SEIRSモードにおける健康状態ごとの色（S=灰, E=黄, I=赤, R=緑）
+fn health_color(health: &Health) -> String {
+    match health {
+        Health::Susceptible => String::from("rgb(160, 160, 160, 1)"),
+        Health::Exposed { .. } => String::from("rgb(240, 220, 40, 1)"),
+        Health::Infected => String::from("rgb(220, 40, 40, 1)"),
+        Health::Recovered => String::from("rgb(40, 200, 80, 1)"),
+    }
+}
+
 // 乱数生成
 fn random_number(low:f64,high:f64) -> f64{
     rand::thread_rng().gen_range(low, high)
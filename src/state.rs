@@ -0,0 +1,63 @@
+// Appの画面/モードを切り替えるステートマシン
+use crate::App;
+
+pub(crate) trait AppState {
+    // 1ティック分の更新。状態遷移が必要であればTransitionを返す
+    fn update(&mut self, app: &mut App, dt: f64) -> Option<Transition>;
+    fn render(&self, app: &App);
+    // PausedStateのみtrueを返し、トグル時にどちらへ戻るかの判断に使う
+    fn is_paused(&self) -> bool {
+        false
+    }
+}
+
+// 状態遷移の要求
+pub(crate) enum Transition {
+    Start,       // MenuState -> RunningState
+    TogglePause, // RunningState <-> PausedState
+}
+
+// シミュレーションを実行する通常状態
+pub(crate) struct RunningState;
+
+impl AppState for RunningState {
+    fn update(&mut self, app: &mut App, dt: f64) -> Option<Transition> {
+        app.tick(dt);
+        None
+    }
+
+    fn render(&self, app: &App) {
+        app.render_scene();
+    }
+}
+
+// 一時停止状態：motionは止めつつ、暗くしたオーバーレイ付きで描画だけ続ける
+pub(crate) struct PausedState;
+
+impl AppState for PausedState {
+    fn update(&mut self, _app: &mut App, _dt: f64) -> Option<Transition> {
+        None
+    }
+
+    fn render(&self, app: &App) {
+        app.render_scene();
+        app.render_pause_overlay();
+    }
+
+    fn is_paused(&self) -> bool {
+        true
+    }
+}
+
+// シミュレーション開始前に操作方法を案内する画面
+pub(crate) struct MenuState;
+
+impl AppState for MenuState {
+    fn update(&mut self, _app: &mut App, _dt: f64) -> Option<Transition> {
+        None
+    }
+
+    fn render(&self, app: &App) {
+        app.render_menu();
+    }
+}